@@ -37,6 +37,12 @@ fn main() {
     attempt8_manually_drop();
     attempt9_const_generics();
 
+    mitigation1_zeroizing();
+    mitigation2_secret_bytes();
+    mitigation3_encrypted();
+    mitigation4_secure_cmp();
+    mitigation5_enclave_sealing();
+
     print_summary();
 }
 
@@ -196,6 +202,10 @@ fn attempt4_unsafe_transmute() {
 // ============================================================================
 // ATTEMPT 5: Raw Pointers and Pointer Arithmetic
 // ============================================================================
+// Boxed rather than a bare `String`: the attack below takes a raw pointer to
+// the `Box` field and dereferences through it to reach the heap-allocated
+// `String`.
+#[allow(clippy::box_collection)]
 struct SecureBox {
     secret: Box<String>,
 }
@@ -396,6 +406,645 @@ fn attempt9_const_generics() {
     println!();
 }
 
+// ============================================================================
+// MITIGATION 1: Zeroizing<T> / secret_string — scrub memory on drop
+// ============================================================================
+// Every attempt above (attempt1::SecretHolder, OpaqueSecret, SecretArray,
+// SecureBox) leaves its secret bytes sitting in memory after the value is
+// dropped, so a later heap/stack scan recovers them. `Zeroizing<T>` and
+// `SecretString` below actually overwrite that memory on drop, and do it in
+// a way the optimizer can't remove: writes go through `write_volatile`, then
+// a compiler fence and a hardware fence pin them in place so they can't be
+// reordered past (or dead-code-eliminated after) the point where the value
+// goes out of scope.
+mod zeroizing {
+    use core::sync::atomic::{compiler_fence, fence, Ordering};
+
+    /// Overwrites `bytes` with zeros such that the writes cannot be elided
+    /// or reordered away by the optimizer.
+    pub(crate) fn zeroize_bytes(bytes: &mut [u8]) {
+        for byte in bytes.iter_mut() {
+            unsafe { core::ptr::write_volatile(byte, 0) };
+        }
+        // Prevent the compiler from reordering the volatile writes past this
+        // point, then fence the hardware too so no later read can observe
+        // pre-zeroed bytes via a reordered store.
+        compiler_fence(Ordering::SeqCst);
+        fence(Ordering::SeqCst);
+    }
+
+    /// Wraps any `T: AsMut<[u8]>` so its backing bytes are scrubbed to zero
+    /// the moment the wrapper drops, instead of being left for whoever reuses
+    /// the freed memory to read.
+    pub struct Zeroizing<T: AsMut<[u8]>> {
+        inner: T,
+    }
+
+    impl<T: AsMut<[u8]>> Zeroizing<T> {
+        pub fn new(inner: T) -> Self {
+            Zeroizing { inner }
+        }
+    }
+
+    impl<T: AsMut<[u8]>> std::ops::Deref for Zeroizing<T> {
+        type Target = T;
+        fn deref(&self) -> &T {
+            &self.inner
+        }
+    }
+
+    impl<T: AsMut<[u8]>> std::ops::DerefMut for Zeroizing<T> {
+        fn deref_mut(&mut self) -> &mut T {
+            &mut self.inner
+        }
+    }
+
+    impl<T: AsMut<[u8]>> Drop for Zeroizing<T> {
+        fn drop(&mut self) {
+            zeroize_bytes(self.inner.as_mut());
+        }
+    }
+
+    /// A `String` that zeros its *entire* backing allocation — `capacity()`,
+    /// not just `len()` — before the allocator frees it. `String::as_mut_vec`
+    /// only exposes the first `len()` bytes, but a prior `reserve`/push/pop
+    /// cycle can leave stale plaintext sitting in the unused capacity, so
+    /// this reaches past `len()` with a raw slice over the whole allocation.
+    pub struct SecretString {
+        value: String,
+    }
+
+    impl SecretString {
+        pub fn new(value: String) -> Self {
+            SecretString { value }
+        }
+
+        pub fn as_str(&self) -> &str {
+            &self.value
+        }
+    }
+
+    impl Drop for SecretString {
+        fn drop(&mut self) {
+            unsafe {
+                let vec = self.value.as_mut_vec();
+                let ptr = vec.as_mut_ptr();
+                let cap = vec.capacity();
+                zeroize_bytes(core::slice::from_raw_parts_mut(ptr, cap));
+            }
+        }
+    }
+}
+
+fn mitigation1_zeroizing() {
+    use zeroizing::{SecretString, Zeroizing};
+
+    println!("MITIGATION 1: Zeroizing<T> / secret_string");
+    println!("{}", "-".repeat(80));
+
+    {
+        let mut key = Zeroizing::new([0x42u8; 32]);
+        key[0] = 0xAA;
+        println!("  Secret held in Zeroizing<[u8; 32]>, first byte: 0x{:02X}", key[0]);
+    }
+    println!("  ✓ SCRUBBED: Zeroizing<T>::drop overwrote the 32 bytes with volatile zero writes");
+
+    {
+        let mut password = String::from("super-secret-password");
+        password.reserve(64); // leaves stale capacity beyond len()
+        let secret = SecretString::new(password);
+        println!("  Secret held in SecretString, len: {}", secret.as_str().len());
+    }
+    println!("  ✓ SCRUBBED: SecretString::drop zeroed the full capacity(), not just len()");
+    println!("  Defense: unlike ManuallyDrop (controls timing, not access), this actually");
+    println!("  erases the bytes, shrinking the exfiltration window to zero after drop");
+    println!();
+}
+
+// ============================================================================
+// MITIGATION 2: mlock-backed SecretBytes with guard pages
+// ============================================================================
+// SecureBox and OpaqueSecret store secrets in ordinary swappable memory, so
+// the OS can page them to disk where they survive indefinitely. SecretBytes
+// allocates page-aligned memory directly, `mlock`s it so it never swaps, and
+// surrounds it with two `PROT_NONE` guard pages so an out-of-bounds pointer
+// walk like attempt5_raw_pointers faults instead of reading adjacent
+// secrets. The data page itself defaults to `PROT_NONE` too, only becoming
+// readable/writable for the lifetime of a `borrow()`/`borrow_mut()` guard.
+mod secret_bytes {
+    use core::sync::atomic::{compiler_fence, fence, AtomicIsize, Ordering};
+    use std::ptr::NonNull;
+
+    const PAGE_SIZE: usize = 4096;
+
+    fn round_up_to_page(n: usize) -> usize {
+        n.max(1).div_ceil(PAGE_SIZE) * PAGE_SIZE
+    }
+
+    pub struct SecretBytes {
+        base: NonNull<u8>,
+        data: NonNull<u8>,
+        map_len: usize,
+        /// The page-rounded size of the mapping backing `data` -- always
+        /// `>= logical_len`, padded out to `PAGE_SIZE`.
+        data_len: usize,
+        /// The caller-requested length passed to `new`. `borrow`/`borrow_mut`
+        /// expose exactly this many bytes, never the page-rounded padding,
+        /// so callers that need an exact-length key/nonce (e.g. `Encrypted`)
+        /// don't have to slice the padding off themselves.
+        logical_len: usize,
+        /// Counts live `borrow`/`borrow_mut` guards; the region is
+        /// `PROT_NONE` whenever this is zero and `PROT_READ|PROT_WRITE`
+        /// while any guard is outstanding.
+        lock_count: AtomicIsize,
+    }
+
+    unsafe impl Send for SecretBytes {}
+    unsafe impl Sync for SecretBytes {}
+
+    impl SecretBytes {
+        /// Allocates `len` usable bytes, page-aligned and flanked by guard
+        /// pages, and `mlock`s the data page so it can't be swapped out.
+        /// Returns `Err` instead of aborting the process if the host
+        /// can't back the request (e.g. `mmap` is out of address space, or
+        /// `RLIMIT_MEMLOCK` is too low for `mlock` to succeed) -- this is
+        /// the backbone of mitigations 2 and 3, so a call site needing to
+        /// keep running without a pinned region should be able to.
+        pub fn new(len: usize) -> Result<Self, String> {
+            let data_len = round_up_to_page(len);
+            let map_len = data_len + 2 * PAGE_SIZE;
+
+            unsafe {
+                let base = libc::mmap(
+                    std::ptr::null_mut(),
+                    map_len,
+                    libc::PROT_NONE,
+                    libc::MAP_PRIVATE | libc::MAP_ANONYMOUS,
+                    -1,
+                    0,
+                );
+                if base == libc::MAP_FAILED {
+                    return Err(format!(
+                        "mmap failed to reserve secret region: {}",
+                        std::io::Error::last_os_error()
+                    ));
+                }
+
+                let data = (base as *mut u8).add(PAGE_SIZE);
+                // mlock needs the page readable/writable to fault it in and
+                // pin it; mmap left it PROT_NONE, so make it accessible
+                // first and drop it back to PROT_NONE once locked.
+                libc::mprotect(data as *mut libc::c_void, data_len, libc::PROT_READ | libc::PROT_WRITE);
+                let rc = libc::mlock(data as *const libc::c_void, data_len);
+                if rc != 0 {
+                    let err = std::io::Error::last_os_error();
+                    libc::munmap(base, map_len);
+                    return Err(format!("mlock failed to pin secret pages: {}", err));
+                }
+                libc::mprotect(data as *mut libc::c_void, data_len, libc::PROT_NONE);
+
+                Ok(SecretBytes {
+                    base: NonNull::new_unchecked(base as *mut u8),
+                    data: NonNull::new_unchecked(data),
+                    map_len,
+                    data_len,
+                    logical_len: len,
+                    lock_count: AtomicIsize::new(0),
+                })
+            }
+        }
+
+        fn set_accessible(&self, accessible: bool) {
+            let prot = if accessible { libc::PROT_READ | libc::PROT_WRITE } else { libc::PROT_NONE };
+            unsafe {
+                libc::mprotect(self.data.as_ptr() as *mut libc::c_void, self.data_len, prot);
+            }
+        }
+
+        /// Grants read access for the lifetime of the returned guard,
+        /// re-protecting the region to `PROT_NONE` once the last guard for
+        /// this allocation drops.
+        pub fn borrow(&self) -> SecretBytesGuard<'_> {
+            if self.lock_count.fetch_add(1, Ordering::AcqRel) == 0 {
+                self.set_accessible(true);
+            }
+            SecretBytesGuard { owner: self }
+        }
+
+        /// Same access window as `borrow`; kept as a separate constructor so
+        /// call sites document mutable vs read-only intent even though both
+        /// guards expose the same underlying pages.
+        pub fn borrow_mut(&self) -> SecretBytesGuard<'_> {
+            self.borrow()
+        }
+    }
+
+    impl Drop for SecretBytes {
+        fn drop(&mut self) {
+            unsafe {
+                self.set_accessible(true);
+                for i in 0..self.data_len {
+                    core::ptr::write_volatile(self.data.as_ptr().add(i), 0);
+                }
+                compiler_fence(Ordering::SeqCst);
+                fence(Ordering::SeqCst);
+                libc::munlock(self.data.as_ptr() as *const libc::c_void, self.data_len);
+                libc::munmap(self.base.as_ptr() as *mut libc::c_void, self.map_len);
+            }
+        }
+    }
+
+    /// RAII access window: exposes the pinned pages as `AsRef`/`AsMut` byte
+    /// slices while alive, then re-protects them to `PROT_NONE` on drop if
+    /// no other guard is still outstanding.
+    pub struct SecretBytesGuard<'a> {
+        owner: &'a SecretBytes,
+    }
+
+    impl<'a> AsRef<[u8]> for SecretBytesGuard<'a> {
+        fn as_ref(&self) -> &[u8] {
+            unsafe { core::slice::from_raw_parts(self.owner.data.as_ptr(), self.owner.logical_len) }
+        }
+    }
+
+    impl<'a> AsMut<[u8]> for SecretBytesGuard<'a> {
+        fn as_mut(&mut self) -> &mut [u8] {
+            unsafe { core::slice::from_raw_parts_mut(self.owner.data.as_ptr(), self.owner.logical_len) }
+        }
+    }
+
+    impl<'a> Drop for SecretBytesGuard<'a> {
+        fn drop(&mut self) {
+            if self.owner.lock_count.fetch_sub(1, Ordering::AcqRel) == 1 {
+                self.owner.set_accessible(false);
+            }
+        }
+    }
+}
+
+fn mitigation2_secret_bytes() {
+    use secret_bytes::SecretBytes;
+
+    println!("MITIGATION 2: mlock-backed SecretBytes with guard pages");
+    println!("{}", "-".repeat(80));
+
+    match SecretBytes::new(32) {
+        Ok(secret) => {
+            {
+                let mut guard = secret.borrow_mut();
+                guard.as_mut()[..5].copy_from_slice(b"s3cr3");
+                println!("  Wrote 5 bytes while the access guard was alive: {:?}", &guard.as_ref()[..5]);
+            }
+            println!("  ✓ PROTECTED: pages reverted to PROT_NONE the instant the guard dropped");
+        }
+        Err(e) => println!("  (mlock unavailable here) {}", e),
+    }
+    println!("  Defense: mlock keeps the secret out of swap, and the surrounding PROT_NONE");
+    println!("  guard pages turn an out-of-bounds walk like attempt5_raw_pointers into a");
+    println!("  segfault instead of a silent read of adjacent secrets");
+    println!();
+}
+
+// ============================================================================
+// MITIGATION 3: Encrypted — ciphertext at rest, plaintext only mid-closure
+// ============================================================================
+// Long-lived keys held by AsyncSecret or sealed_secret::Secret are plaintext
+// in RAM for the object's whole lifetime, so any memory dump catches them.
+// Encrypted keeps the secret encrypted with an ephemeral ChaCha20 key/nonce
+// (itself pinned in a SecretBytes region) and only ever materializes
+// plaintext inside `map`, into a temporary buffer that `zeroize_bytes` scrubs
+// the moment the closure returns. This directly counters the "public methods must expose
+// data" claim by minimizing when plaintext exists at all.
+// ChaCha20 round trip (encrypt in `new`, decrypt in `map`) verified end to
+// end against real demo output now that `SecretBytes::new`'s mlock ordering
+// no longer aborts the process before mitigation 3 runs.
+mod encrypted {
+    use super::secret_bytes::SecretBytes;
+    use chacha20::cipher::{KeyIvInit, StreamCipher};
+    use chacha20::ChaCha20;
+    use rand::RngCore;
+
+    const KEY_LEN: usize = 32;
+    const NONCE_LEN: usize = 12;
+
+    pub struct Encrypted {
+        ciphertext: Vec<u8>,
+        // Holds `KEY_LEN` key bytes followed by `NONCE_LEN` nonce bytes,
+        // pinned and guard-paged like any other long-lived secret.
+        key_material: SecretBytes,
+    }
+
+    impl Encrypted {
+        /// Encrypts `plaintext` under a fresh random key/nonce, then zeros
+        /// the caller's buffer so no plaintext copy survives construction.
+        pub fn new(plaintext: &mut [u8]) -> Result<Self, String> {
+            let key_material = SecretBytes::new(KEY_LEN + NONCE_LEN)?;
+            {
+                let mut guard = key_material.borrow_mut();
+                rand::thread_rng().fill_bytes(guard.as_mut());
+            }
+
+            let mut ciphertext = plaintext.to_vec();
+            {
+                let guard = key_material.borrow();
+                let bytes = guard.as_ref();
+                let mut cipher = ChaCha20::new(bytes[..KEY_LEN].into(), bytes[KEY_LEN..].into());
+                cipher.apply_keystream(&mut ciphertext);
+            }
+
+            super::zeroizing::zeroize_bytes(plaintext);
+
+            Ok(Encrypted { ciphertext, key_material })
+        }
+
+        /// Decrypts into a temporary zeroizing buffer, runs `f` against it,
+        /// then scrubs and frees the buffer — plaintext lifetime is bounded
+        /// by this call, not by `Encrypted`'s lifetime.
+        pub fn map<F, R>(&self, f: F) -> R
+        where
+            F: FnOnce(&[u8]) -> R,
+        {
+            let mut buf = self.ciphertext.clone();
+            {
+                let guard = self.key_material.borrow();
+                let bytes = guard.as_ref();
+                let mut cipher = ChaCha20::new(bytes[..KEY_LEN].into(), bytes[KEY_LEN..].into());
+                cipher.apply_keystream(&mut buf);
+            }
+            let result = f(&buf);
+            super::zeroizing::zeroize_bytes(&mut buf);
+            result
+        }
+    }
+}
+
+fn mitigation3_encrypted() {
+    use encrypted::Encrypted;
+
+    println!("MITIGATION 3: Encrypted — ciphertext at rest");
+    println!("{}", "-".repeat(80));
+
+    let mut plaintext = b"top-secret-api-key".to_vec();
+    match Encrypted::new(&mut plaintext) {
+        Ok(secret) => {
+            println!("  Caller's buffer after construction: {:?} (zeroed)", plaintext);
+
+            let revealed = secret.map(|bytes| String::from_utf8_lossy(bytes).into_owned());
+            println!("  Decrypted only inside map(): {}", revealed);
+            println!("  ✓ PROTECTED: plaintext existed only for the duration of the map() closure");
+        }
+        Err(e) => println!("  (mlock unavailable here) {}", e),
+    }
+    println!("  Defense: a memory dump taken outside of map() finds only ciphertext and an");
+    println!("  mlocked ephemeral key, not the long-lived plaintext attempt7 keeps around");
+    println!();
+}
+
+// ============================================================================
+// MITIGATION 4: secure_cmp / ConstantTimeEq — no timing side channel
+// ============================================================================
+// The getters in this file (attempt1::SecretHolder::get_key,
+// sealed_secret::Secret::reveal, attempt4's OpaqueSecret::get_secret) all
+// return plain &str, inviting callers to compare secrets with `==`, which
+// short-circuits on the first differing byte and leaks length/prefix
+// information through timing. secure_cmp/ConstantTimeEq always scan every
+// byte of the shorter input, fold in the length difference, and never
+// branch on byte values.
+mod constant_time {
+    use std::cmp::Ordering;
+    use std::hint::black_box;
+
+    /// Compares `a` and `b` without branching on their contents. Scans every
+    /// byte of the common prefix, folding `diff |= a[i] ^ b[i]`, then folds
+    /// the length difference into the same accumulator so mismatched
+    /// lengths never compare equal. Equality is reported iff the final
+    /// accumulator is zero; `black_box` stops the compiler from proving the
+    /// loop's outcome early and reintroducing an early exit.
+    ///
+    /// The returned `Ordering` only distinguishes equal from not-equal —
+    /// not-equal is always `Greater` and carries no magnitude information, since reporting
+    /// which side is "bigger" would itself leak data a timing-safe
+    /// comparison is meant to hide.
+    pub fn secure_cmp(a: &[u8], b: &[u8]) -> Ordering {
+        let min_len = a.len().min(b.len());
+        let mut diff: u8 = 0;
+        for i in 0..min_len {
+            let av = unsafe { core::ptr::read_volatile(&a[i]) };
+            let bv = unsafe { core::ptr::read_volatile(&b[i]) };
+            diff |= av ^ bv;
+        }
+        diff |= (a.len() != b.len()) as u8;
+        if black_box(diff) == 0 {
+            Ordering::Equal
+        } else {
+            Ordering::Greater
+        }
+    }
+
+    /// Constant-time equality for secret byte/string data.
+    pub trait ConstantTimeEq {
+        fn ct_eq(&self, other: &Self) -> bool;
+    }
+
+    impl ConstantTimeEq for [u8] {
+        fn ct_eq(&self, other: &Self) -> bool {
+            secure_cmp(self, other) == Ordering::Equal
+        }
+    }
+
+    impl ConstantTimeEq for str {
+        fn ct_eq(&self, other: &Self) -> bool {
+            self.as_bytes().ct_eq(other.as_bytes())
+        }
+    }
+
+    impl ConstantTimeEq for super::zeroizing::SecretString {
+        fn ct_eq(&self, other: &Self) -> bool {
+            self.as_str().ct_eq(other.as_str())
+        }
+    }
+}
+
+fn mitigation4_secure_cmp() {
+    use constant_time::ConstantTimeEq;
+
+    println!("MITIGATION 4: secure_cmp / ConstantTimeEq");
+    println!("{}", "-".repeat(80));
+
+    let a = zeroizing::SecretString::new("sk-PRODUCTION-SECRET-12345".to_string());
+    let b = zeroizing::SecretString::new("sk-PRODUCTION-SECRET-12345".to_string());
+    let c = zeroizing::SecretString::new("wrong-guess".to_string());
+
+    println!("  a.ct_eq(&b) [same secret]: {}", a.ct_eq(&b));
+    println!("  a.ct_eq(&c) [wrong guess]: {}", a.ct_eq(&c));
+    println!("  ✓ PROTECTED: both comparisons scan every byte of the shorter input");
+    println!("  Defense: unlike `==` (short-circuits on the first differing byte), the");
+    println!("  number of byte-compares depends only on the input lengths, not on where or");
+    println!("  whether a mismatch occurs, closing the timing side channel the getters open");
+    println!();
+}
+
+// ============================================================================
+// MITIGATION 5: SGX enclave sealing — ciphertext bound to CPU measurement
+// ============================================================================
+// print_summary's conclusion is "no runtime protection exists," but
+// trusted-execution hardware changes that: secrets sealed inside an enclave
+// are encrypted with a CPU-fused key and are inaccessible to gdb, raw
+// pointer tricks, or the host OS. Building on the Teaclave SGX SDK
+// toolchain, operations that touch plaintext run via an ECALL inside the
+// enclave, so cleartext never crosses the FFI boundary attempt6_ffi_boundary
+// exploits. Without the `sgx` feature this compiles to a stub that returns a
+// clear "unsupported" error rather than silently falling back to plaintext.
+#[cfg(feature = "sgx")]
+mod enclave {
+    use super::secret_bytes::SecretBytes;
+    use sgx_types::{sgx_enclave_id_t, sgx_launch_token_t, sgx_misc_attribute_t};
+    use sgx_urts::SgxEnclave;
+
+    /// Ciphertext bound to the sealing enclave's measurement. Safe to
+    /// persist or pass through FFI -- it decrypts to nothing outside that
+    /// enclave.
+    pub struct SealedBlob {
+        pub ciphertext: Vec<u8>,
+    }
+
+    impl SealedBlob {
+        pub fn len(&self) -> usize {
+            self.ciphertext.len()
+        }
+    }
+
+    pub struct Enclave {
+        id: sgx_enclave_id_t,
+    }
+
+    extern "C" {
+        fn ecall_seal(
+            eid: sgx_enclave_id_t,
+            secret_ptr: *const u8,
+            secret_len: usize,
+            out_ptr: *mut u8,
+            out_cap: usize,
+            out_len: *mut usize,
+        ) -> i32;
+        fn ecall_unseal(
+            eid: sgx_enclave_id_t,
+            blob_ptr: *const u8,
+            blob_len: usize,
+            out_ptr: *mut u8,
+            out_cap: usize,
+            out_len: *mut usize,
+        ) -> i32;
+    }
+
+    impl Enclave {
+        pub fn launch() -> Result<Self, String> {
+            let mut launch_token: sgx_launch_token_t = [0; 1024];
+            let mut launch_token_updated: i32 = 0;
+            let mut misc_attr = sgx_misc_attribute_t::default();
+            SgxEnclave::create(
+                "enclave.signed.so",
+                1, // debug enclave
+                &mut launch_token,
+                &mut launch_token_updated,
+                &mut misc_attr,
+            )
+            .map(|enclave| Enclave { id: enclave.geteid() })
+            .map_err(|e| format!("failed to launch enclave: {:?}", e))
+        }
+
+        /// Seals `secret` under the enclave's CPU-fused key. The resulting
+        /// blob never contains cleartext, so it's safe to persist or hand
+        /// across the host/enclave FFI boundary.
+        pub fn seal(&self, secret: &[u8]) -> Result<SealedBlob, String> {
+            let mut out = vec![0u8; secret.len() + 1024]; // seal MAC/metadata overhead
+            let mut out_len = 0usize;
+            let rc = unsafe {
+                ecall_seal(self.id, secret.as_ptr(), secret.len(), out.as_mut_ptr(), out.len(), &mut out_len)
+            };
+            if rc != 0 {
+                return Err(format!("ecall_seal failed: {}", rc));
+            }
+            out.truncate(out_len);
+            Ok(SealedBlob { ciphertext: out })
+        }
+
+        /// Unseals `blob` inside the enclave and copies the result into an
+        /// mlocked `SecretBytes` region on the host side -- the only point
+        /// at which plaintext exists outside the enclave boundary.
+        pub fn unseal(&self, blob: &SealedBlob) -> Result<SecretBytes, String> {
+            let cap = blob.ciphertext.len();
+            let mut out = vec![0u8; cap];
+            let mut out_len = 0usize;
+            let rc = unsafe {
+                ecall_unseal(self.id, blob.ciphertext.as_ptr(), blob.ciphertext.len(), out.as_mut_ptr(), cap, &mut out_len)
+            };
+            if rc != 0 {
+                return Err(format!("ecall_unseal failed: {}", rc));
+            }
+            let secret = SecretBytes::new(out_len);
+            if let Ok(secret) = &secret {
+                secret.borrow_mut().as_mut().copy_from_slice(&out[..out_len]);
+            }
+            super::zeroizing::zeroize_bytes(&mut out);
+            secret
+        }
+    }
+}
+
+/// Stub backend for builds without the `sgx` feature: returns a clear "unsupported"
+/// error on every operation instead of silently falling back to plaintext.
+#[cfg(not(feature = "sgx"))]
+mod enclave {
+    pub struct SealedBlob {
+        _private: (),
+    }
+
+    impl SealedBlob {
+        pub fn len(&self) -> usize {
+            0
+        }
+    }
+
+    pub struct Enclave {
+        _private: (),
+    }
+
+    impl Enclave {
+        pub fn launch() -> Result<Self, String> {
+            Err("SGX support not compiled in (rebuild with --features sgx)".to_string())
+        }
+
+        pub fn seal(&self, _secret: &[u8]) -> Result<SealedBlob, String> {
+            Err("SGX support not compiled in (rebuild with --features sgx)".to_string())
+        }
+
+        pub fn unseal(&self, _blob: &SealedBlob) -> Result<super::secret_bytes::SecretBytes, String> {
+            Err("SGX support not compiled in (rebuild with --features sgx)".to_string())
+        }
+    }
+}
+
+fn mitigation5_enclave_sealing() {
+    println!("MITIGATION 5: SGX enclave sealing");
+    println!("{}", "-".repeat(80));
+
+    match enclave::Enclave::launch() {
+        Ok(enc) => match enc.seal(b"sk-PRODUCTION-SECRET-12345") {
+            Ok(blob) => {
+                println!("  ✓ PROTECTED: sealed to {} bytes of enclave-bound ciphertext", blob.len());
+                let _ = enc.unseal(&blob);
+            }
+            Err(e) => println!("  seal failed: {}", e),
+        },
+        Err(e) => println!("  (no SGX backend available here) {}", e),
+    }
+    println!("  Defense: sealed blobs are ciphertext bound to the enclave's measurement --");
+    println!("  gdb, raw pointer tricks (attempt5), and the FFI boundary (attempt6) only see");
+    println!("  ciphertext; cleartext exists only inside the enclave's protected memory");
+    println!();
+}
+
 // ============================================================================
 // SUMMARY
 // ============================================================================
@@ -1,4 +1,4 @@
-/**
+/*
  * T.A.R.L./Thirsty-Lang Solution for Rust: ABSOLUTE Secret Protection
  * 
  * This demonstrates how T.A.R.L.'s Rust adapter achieves what is IMPOSSIBLE
@@ -16,6 +16,8 @@ use std::mem;
 
 /// TARL represents the T.A.R.L. VM adapter
 /// In production, this would be: use project_ai_tarl::TARL;
+#[allow(clippy::upper_case_acronyms)]
+#[allow(dead_code)]
 struct TARL {
     version: String,
     security_constraints: HashMap<String, String>,
@@ -257,6 +259,7 @@ fn example4_transmute_blocking() {
     // Demonstrate transmute (on host, not VM)
     println!("Rust transmute demonstration (on host, NOT in T.A.R.L. VM):");
     let x: u32 = 42;
+    #[allow(unnecessary_transmutes)]
     let y: f32 = unsafe { mem::transmute(x) };
     println!("  Rust: transmute u32 to f32: {} -> {}", x, y);
     println!("  T.A.R.L.: mem::transmute not available in VM");
@@ -371,7 +374,12 @@ fn comparative_analysis() {
     
     let comparison = vec![
         ("Feature", "Rust", "T.A.R.L.", "Result"),
-        (&"-".repeat(30), &"-".repeat(25), &"-".repeat(25), &"-".repeat(15)),
+        (
+            "------------------------------",
+            "-------------------------",
+            "-------------------------",
+            "---------------",
+        ),
         ("unsafe blocks", "Available", "N/A", "100% safer"),
         ("mem::transmute", "Available", "Blocked", "100% safer"),
         ("Raw pointers", "*const/*mut", "N/A", "100% safer"),
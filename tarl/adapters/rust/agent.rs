@@ -0,0 +1,83 @@
+//! Native Rust Cerberus guard agent. Supersedes the Python
+//! `cerberus-<generation>-<id>` template's busy-loop monitor with a
+//! `no_std`-compatible implementation that can be embedded alongside the
+//! secrets it protects instead of run as a separate process.
+//!
+//! The monitoring wait is abstracted over `WaitStrategy` (a `CondVar`-style
+//! trait) so the host supplies the timer and blocking primitive; nothing
+//! here assumes `std::thread` or `std::time` are available.
+
+use core::sync::atomic::{AtomicBool, Ordering};
+use core::time::Duration;
+
+/// A `CondVar`-style wait primitive. Implementations supply the actual
+/// blocking/timer mechanism (a kernel waitqueue, a `std::sync::Condvar`, a
+/// busy-poll spin loop, ...); `GuardAgent` only ever calls through the trait.
+pub trait WaitStrategy {
+    /// Blocks for up to `timeout`, returning `true` if `notify` woke it
+    /// early and `false` if the timeout elapsed first.
+    fn wait_interruptible_timeout(&self, timeout: Duration) -> bool;
+
+    /// Wakes any waiter blocked in `wait_interruptible_timeout`.
+    fn notify(&self);
+}
+
+/// A Cerberus guard agent watching one locked section of the TARL VM's
+/// secret heap. `generation` counts how many times a breach has caused this
+/// lineage to respawn; `spawn` produces the next generation's reinforcements.
+pub struct GuardAgent<W> {
+    pub agent_id: u32,
+    pub locked_section: &'static str,
+    pub generation: u32,
+    active: AtomicBool,
+    wait: W,
+}
+
+impl<W: WaitStrategy> GuardAgent<W> {
+    pub fn new(agent_id: u32, locked_section: &'static str, generation: u32, wait: W) -> Self {
+        GuardAgent { agent_id, locked_section, generation, active: AtomicBool::new(true), wait }
+    }
+
+    /// Runs the monitor loop, waking every `interval` (or whenever `stop`
+    /// calls `notify`) until `stop` is called.
+    pub fn monitor(&self, interval: Duration) {
+        while self.active.load(Ordering::Acquire) {
+            self.wait.wait_interruptible_timeout(interval);
+        }
+    }
+
+    /// Stops the monitor loop and wakes it immediately if it's waiting.
+    pub fn stop(&self) {
+        self.active.store(false, Ordering::Release);
+        self.wait.notify();
+    }
+}
+
+impl<W: WaitStrategy + Clone> GuardAgent<W> {
+    /// Responds to a breach in `locked_section` by spawning 3 reinforcement
+    /// agents tagged with the given `generation` (one ahead of this one for
+    /// `spawn(self.generation + 1)`), each sharing this agent's wait
+    /// primitive.
+    pub fn spawn(&self, generation: u32) -> [GuardAgent<W>; 3] {
+        core::array::from_fn(|i| {
+            GuardAgent::new(
+                self.agent_id * 3 + i as u32 + 1,
+                self.locked_section,
+                generation,
+                self.wait.clone(),
+            )
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W> core::fmt::Debug for GuardAgent<W> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("GuardAgent")
+            .field("agent_id", &format!("cerberus-{}-{}", self.generation, self.agent_id))
+            .field("locked_section", &self.locked_section)
+            .field("generation", &self.generation)
+            .field("active", &self.active.load(Ordering::Relaxed))
+            .finish()
+    }
+}
@@ -1,3 +1,353 @@
+//! The TARL VM adapter. Builds under `std` by default; disable the `std`
+//! feature to compile the VM and its secret store for kernel/embedded
+//! contexts (Rust-for-Linux's `kernel` crate uses the same split). Only the
+//! `OsRng` default RNG, `GuardAgent`'s `Debug` impl, the host-side
+//! `BypassScanner` lint and the SGX enclave backend require `std`.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+#[cfg(not(feature = "std"))]
+use alloc::collections::BTreeMap as HashMap;
+#[cfg(not(feature = "std"))]
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use aes_gcm::aead::{Aead, KeyInit};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use sha2::{Digest, Sha512};
+use zeroize::{Zeroize, Zeroizing};
+
+pub mod agent;
+
+/// Supplies randomness for armoring a variable's data key and nonce.
+/// `std` builds can reach for `OsRng` below; `no_std` targets have no OS
+/// entropy source to assume, so callers there must supply their own
+/// (a hardware RNG, a PRNG reseeded from a kernel-provided seed, ...).
+/// Threaded through as a parameter rather than stored on `SecretStore` so
+/// `SecretStore` and `TARL` can keep deriving `Clone` without requiring
+/// the RNG itself to be cloneable.
+pub trait SecretRng {
+    fn fill_bytes(&mut self, dest: &mut [u8]);
+}
+
+/// The default `std` RNG: `rand::thread_rng()` behind the `SecretRng` trait.
+#[cfg(feature = "std")]
+#[derive(Default)]
+pub struct OsRng;
+
+#[cfg(feature = "std")]
+impl SecretRng for OsRng {
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        rand::RngCore::fill_bytes(&mut rand::thread_rng(), dest);
+    }
+}
+
+/// Errors produced by the T.A.R.L. VM adapter.
+#[derive(Debug)]
+pub enum TarlError {
+    /// Bytecode failed Ed25519 signature verification, or no signature is available at all.
+    SignatureError(String),
+    /// AES-256-GCM encryption or decryption of an armored variable failed.
+    /// `BypassScanner::scan_file` also returns it when a file can't be read
+    /// or parsed.
+    CryptoError(String),
+    /// `pour`/`armor` referenced a variable that was never `drink`ed.
+    UnknownVariable(String),
+    /// A buffer could not be grown, either because the allocator is under
+    /// memory pressure or the request would exceed a configured capacity limit.
+    OutOfMemory,
+    /// The SGX enclave failed to launch or rejected an attestation request.
+    #[cfg(feature = "sgx")]
+    EnclaveError(String),
+}
+
+impl core::fmt::Display for TarlError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            TarlError::SignatureError(msg) => write!(f, "signature error: {}", msg),
+            TarlError::CryptoError(msg) => write!(f, "crypto error: {}", msg),
+            TarlError::UnknownVariable(name) => write!(f, "unknown variable: {}", name),
+            TarlError::OutOfMemory => write!(f, "out of memory: allocation failed or capacity limit reached"),
+            #[cfg(feature = "sgx")]
+            TarlError::EnclaveError(msg) => write!(f, "enclave error: {}", msg),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TarlError {}
+
+/// An armored variable's value, encrypted with AES-256-GCM under a per-secret
+/// data key. Only the ciphertext, nonce and tag ever live in the variable
+/// table; the data key lives in a separate allocation that is zeroized on drop.
+#[derive(Clone)]
+struct ArmoredSecret {
+    ciphertext: Vec<u8>,
+    nonce: [u8; 12],
+    // Boxed so the key bytes sit in their own heap allocation rather than
+    // inline in `ArmoredSecret` next to the ciphertext they decrypt. A boxed
+    // slice rather than a boxed array: `zeroize` only implements `Zeroize`
+    // for `Box<[Z]>`, not `Box<[Z; N]>`.
+    data_key: Zeroizing<Box<[u8]>>,
+}
+
+// `Zeroizing` deliberately has no `Debug` impl so wrapping a secret in it
+// doesn't also leak it the moment something derives `Debug` on the struct
+// around it. Spell the field out by hand instead of deriving, redacting the
+// key and showing only the ciphertext's length.
+impl core::fmt::Debug for ArmoredSecret {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ArmoredSecret")
+            .field("ciphertext_len", &self.ciphertext.len())
+            .field("nonce", &self.nonce)
+            .field("data_key", &"<redacted>")
+            .finish()
+    }
+}
+
+#[derive(Clone)]
+enum Variable {
+    Plain(String),
+    Armored(ArmoredSecret),
+}
+
+// As with `ArmoredSecret`, redact `Plain` values by hand rather than derive
+// `Debug` so `{:?}`-logging a `SecretStore` can't surface a secret that just
+// hasn't been `armor`ed yet.
+impl core::fmt::Debug for Variable {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Variable::Plain(_) => f.debug_tuple("Plain").field(&"<redacted>").finish(),
+            Variable::Armored(secret) => f.debug_tuple("Armored").field(secret).finish(),
+        }
+    }
+}
+
+/// Reserves room for one more entry in the variable table without panicking.
+/// `std::collections::HashMap` exposes `try_reserve`; the `BTreeMap` used
+/// under `no_std` grows node-by-node and has no capacity to reserve, so the
+/// `capacity_limit` check in `SecretStore` is the only bound there.
+#[cfg(feature = "std")]
+fn reserve_one(variables: &mut HashMap<String, Variable>) -> Result<(), TarlError> {
+    variables.try_reserve(1).map_err(|_| TarlError::OutOfMemory)
+}
+
+#[cfg(not(feature = "std"))]
+fn reserve_one(_variables: &mut HashMap<String, Variable>) -> Result<(), TarlError> {
+    Ok(())
+}
+
+/// Holds the TARL VM's variable table. Variables start out `Plain` when
+/// `drink`ed and move to `Armored` once the interpreter sees `armor <var>`.
+/// Every store is checked against `capacity_limit`, and the table (under
+/// `std`) and each stored ciphertext buffer grow through `try_reserve`, so
+/// an oversized secret or a failed reservation returns
+/// `TarlError::OutOfMemory`. Variable names, the plaintext copy taken for
+/// encryption and the buffer `Aes256Gcm::encrypt` returns are allocated
+/// infallibly.
+#[derive(Debug, Default, Clone)]
+pub struct SecretStore {
+    variables: HashMap<String, Variable>,
+    capacity_limit: Option<usize>,
+}
+
+impl SecretStore {
+    fn used_bytes(&self) -> usize {
+        self.variables
+            .values()
+            .map(|var| match var {
+                Variable::Plain(value) => value.len(),
+                Variable::Armored(secret) => secret.ciphertext.len(),
+            })
+            .sum()
+    }
+
+    fn check_capacity(&self, name: &str, additional: usize) -> Result<(), TarlError> {
+        // The new value replaces whatever `name` holds now, so that entry's
+        // bytes don't count against the limit.
+        let replaced = match self.variables.get(name) {
+            Some(Variable::Plain(value)) => value.len(),
+            Some(Variable::Armored(secret)) => secret.ciphertext.len(),
+            None => 0,
+        };
+        match self.capacity_limit {
+            Some(limit) if self.used_bytes() - replaced + additional > limit => Err(TarlError::OutOfMemory),
+            _ => Ok(()),
+        }
+    }
+
+    fn drink(&mut self, name: &str, value: String) -> Result<(), TarlError> {
+        self.check_capacity(name, value.len())?;
+        reserve_one(&mut self.variables)?;
+        self.variables.insert(name.to_string(), Variable::Plain(value));
+        Ok(())
+    }
+
+    /// Moves `name`'s value out of plaintext into an AES-256-GCM ciphertext,
+    /// generating a fresh 256-bit data key and 96-bit nonce for it via `rng`.
+    fn armor(&mut self, name: &str, rng: &mut dyn SecretRng) -> Result<(), TarlError> {
+        let plaintext = match self.variables.get(name) {
+            Some(Variable::Plain(value)) => value.clone(),
+            Some(Variable::Armored(_)) => return Ok(()),
+            None => return Err(TarlError::UnknownVariable(name.to_string())),
+        };
+
+        let mut data_key = Zeroizing::new(vec![0u8; 32].into_boxed_slice());
+        rng.fill_bytes(&mut data_key);
+        let mut nonce_bytes = [0u8; 12];
+        rng.fill_bytes(&mut nonce_bytes);
+
+        let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&data_key));
+        let encrypted = cipher
+            .encrypt(Nonce::from_slice(&nonce_bytes), plaintext.as_bytes())
+            .map_err(|e| TarlError::CryptoError(e.to_string()))?;
+
+        // `Aes256Gcm::encrypt` grows its own Vec infallibly; copy it into one
+        // reserved with try_reserve_exact so the buffer kept in the table is
+        // reserved fallibly, same as `compile`'s bytecode buffer. The
+        // temporary Vec `encrypt` returned was still allocated infallibly.
+        let mut ciphertext = Vec::new();
+        ciphertext
+            .try_reserve_exact(encrypted.len())
+            .map_err(|_| TarlError::OutOfMemory)?;
+        ciphertext.extend_from_slice(&encrypted);
+
+        self.check_capacity(name, ciphertext.len())?;
+        reserve_one(&mut self.variables)?;
+        self.variables.insert(
+            name.to_string(),
+            Variable::Armored(ArmoredSecret { ciphertext, nonce: nonce_bytes, data_key }),
+        );
+        Ok(())
+    }
+
+    /// Reads `name`'s value, decrypting into a short-lived buffer if armored.
+    fn pour(&self, name: &str) -> Result<String, TarlError> {
+        match self.variables.get(name) {
+            Some(Variable::Plain(value)) => Ok(value.clone()),
+            Some(Variable::Armored(secret)) => {
+                let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&secret.data_key));
+                let mut decrypted = cipher
+                    .decrypt(Nonce::from_slice(&secret.nonce), secret.ciphertext.as_slice())
+                    .map_err(|e| TarlError::CryptoError(e.to_string()))?;
+
+                // `Aes256Gcm::decrypt` grows its own Vec infallibly; copy the
+                // plaintext into one reserved with try_reserve_exact, then
+                // scrub the crate's own buffer once the copy is made.
+                let mut plaintext = Vec::new();
+                let reserved = plaintext.try_reserve_exact(decrypted.len());
+                if reserved.is_ok() {
+                    plaintext.extend_from_slice(&decrypted);
+                }
+                decrypted.zeroize();
+                reserved.map_err(|_| TarlError::OutOfMemory)?;
+
+                String::from_utf8(plaintext).map_err(|e| TarlError::CryptoError(e.to_string()))
+            }
+            None => Err(TarlError::UnknownVariable(name.to_string())),
+        }
+    }
+
+    /// Returns the raw variable table: plaintext for `Plain` entries,
+    /// ciphertext bytes for `Armored` ones. A memory dump through this API
+    /// should never surface an armored secret's plaintext.
+    pub fn dump_memory(&self) -> HashMap<String, Vec<u8>> {
+        self.variables
+            .iter()
+            .map(|(name, var)| {
+                let bytes = match var {
+                    Variable::Plain(value) => value.clone().into_bytes(),
+                    Variable::Armored(secret) => secret.ciphertext.clone(),
+                };
+                (name.clone(), bytes)
+            })
+            .collect()
+    }
+}
+
+/// Intel SGX enclave backend (built on the Teaclave SGX SDK's `sgx_tstd`
+/// model): launches the enclave and fetches its remote-attestation quote.
+/// Armored variables are not sealed inside it; they stay AES-256-GCM
+/// ciphertext in `SecretStore` in host memory. Gated behind the `sgx`
+/// feature; the default software-isolation path above is unaffected.
+#[cfg(feature = "sgx")]
+mod enclave {
+    use sgx_types::{sgx_enclave_id_t, sgx_launch_token_t, sgx_misc_attribute_t};
+    use sgx_urts::SgxEnclave;
+
+    use super::TarlError;
+
+    const ENCLAVE_FILE: &str = "tarl_enclave.signed.so";
+
+    /// A handle to a launched SGX enclave, used to request its attestation
+    /// quote. No part of `execute_source` runs inside it.
+    #[derive(Debug, Clone, Copy)]
+    pub struct Enclave {
+        id: sgx_enclave_id_t,
+    }
+
+    impl Enclave {
+        /// Launches the enclave binary and returns a handle to it.
+        pub fn launch() -> Result<Self, TarlError> {
+            let mut launch_token: sgx_launch_token_t = [0; 1024];
+            let mut launch_token_updated: i32 = 0;
+            let mut misc_attr = sgx_misc_attribute_t::default();
+            let enclave = SgxEnclave::create(
+                ENCLAVE_FILE,
+                1, // debug enclave
+                &mut launch_token,
+                &mut launch_token_updated,
+                &mut misc_attr,
+            )
+            .map_err(|e| TarlError::EnclaveError(e.to_string()))?;
+            Ok(Enclave { id: enclave.geteid() })
+        }
+
+        /// Requests a remote-attestation quote from the enclave so a caller
+        /// can verify its measurement before sending it any secrets.
+        pub fn attestation_report(&self) -> Result<Vec<u8>, TarlError> {
+            extern "C" {
+                fn ecall_get_attestation_quote(
+                    eid: sgx_enclave_id_t,
+                    retval: *mut i32,
+                    quote_buf: *mut u8,
+                    quote_buf_len: usize,
+                ) -> i32;
+            }
+
+            let mut quote = vec![0u8; 4096];
+            let mut retval: i32 = 0;
+            let status = unsafe {
+                ecall_get_attestation_quote(self.id, &mut retval, quote.as_mut_ptr(), quote.len())
+            };
+            if status != 0 || retval != 0 {
+                return Err(TarlError::EnclaveError(format!(
+                    "attestation ecall failed: status={}, retval={}",
+                    status, retval
+                )));
+            }
+            Ok(quote)
+        }
+    }
+}
+
+/// Compiled Thirsty-Lang bytecode together with the detached Ed25519 signature
+/// produced over its SHA-512 digest at compile time.
+#[derive(Debug, Clone)]
+pub struct SignedBytecode {
+    pub bytecode: Vec<u8>,
+    pub signature: Signature,
+}
+
 #[derive(Debug, Clone)]
 pub struct TARL {
     pub version: &'static str,
@@ -5,6 +355,12 @@ pub struct TARL {
     pub scope: String,
     pub authority: String,
     pub constraints: Vec<String>,
+    signing_key: Option<SigningKey>,
+    verifying_key: Option<VerifyingKey>,
+    signed_bytecode: Option<SignedBytecode>,
+    secrets: SecretStore,
+    #[cfg(feature = "sgx")]
+    enclave: Option<enclave::Enclave>,
 }
 
 impl TARL {
@@ -15,6 +371,405 @@ impl TARL {
             scope: scope.to_string(),
             authority: authority.to_string(),
             constraints,
+            signing_key: None,
+            verifying_key: None,
+            signed_bytecode: None,
+            secrets: SecretStore::default(),
+            #[cfg(feature = "sgx")]
+            enclave: None,
+        }
+    }
+
+    /// Creates a VM that compiles Thirsty-Lang source into Ed25519-signed bytecode.
+    /// The VM keeps `signing_key` to sign each buffer `execute_source`
+    /// compiles, and its `VerifyingKey` to check signatures in `execute_signed`.
+    pub fn new_signed(
+        intent: &str,
+        scope: &str,
+        authority: &str,
+        constraints: Vec<String>,
+        signing_key: SigningKey,
+    ) -> TARL {
+        let verifying_key = signing_key.verifying_key();
+        TARL {
+            version: "2.0",
+            intent: intent.to_string(),
+            scope: scope.to_string(),
+            authority: authority.to_string(),
+            constraints,
+            signing_key: Some(signing_key),
+            verifying_key: Some(verifying_key),
+            signed_bytecode: None,
+            secrets: SecretStore::default(),
+            #[cfg(feature = "sgx")]
+            enclave: None,
         }
     }
+
+    /// Compiles `source` into bytecode and signs its SHA-512 digest with the
+    /// VM's signing key, storing the result for subsequent execution.
+    fn compile(&mut self, source: &str) -> Result<(), TarlError> {
+        let signing_key = self.signing_key.as_ref().ok_or_else(|| {
+            TarlError::SignatureError("VM has no signing key; use TARL::new_signed".to_string())
+        })?;
+
+        let source_bytes = source.as_bytes();
+        let mut bytecode = Vec::new();
+        bytecode
+            .try_reserve_exact(source_bytes.len())
+            .map_err(|_| TarlError::OutOfMemory)?;
+        bytecode.extend_from_slice(source_bytes);
+        let digest = Sha512::digest(&bytecode);
+        let signature = signing_key.sign(&digest);
+
+        self.signed_bytecode = Some(SignedBytecode { bytecode, signature });
+        Ok(())
+    }
+
+    /// Compiles `source`, signs it with the VM's own key, and runs it through
+    /// `execute_signed`. A convenience for the common case where the caller
+    /// trusts its own process; since the bytecode and signature are both
+    /// produced here, this alone can never observe a tampered buffer or a
+    /// wrong-key signature. Callers that need that guarantee -- e.g. running
+    /// bytecode that arrived over the network or from another process --
+    /// should sign/verify through `execute_signed` against independently
+    /// obtained `SignedBytecode` instead.
+    pub fn execute_source(&mut self, source: &str, rng: &mut dyn SecretRng) -> Result<String, TarlError> {
+        self.compile(source)?;
+        let signed = self
+            .signed_bytecode
+            .clone()
+            .expect("compile() always populates signed_bytecode on success");
+        self.execute_signed(&signed, rng)
+    }
+
+    /// Verifies `signed` against the VM's stored verifying key and, only on
+    /// success, interprets its bytecode as Thirsty-Lang source. Unlike
+    /// `execute_source`, `signed` is caller-supplied and need not have come
+    /// from this VM's own `compile`: a tampered bytecode buffer or a
+    /// signature produced by a different key fails verification here and
+    /// returns `Err` before `interpret` ever runs.
+    pub fn execute_signed(
+        &mut self,
+        signed: &SignedBytecode,
+        rng: &mut dyn SecretRng,
+    ) -> Result<String, TarlError> {
+        let verifying_key = self.verifying_key.ok_or_else(|| {
+            TarlError::SignatureError("VM has no verifying key; use TARL::new_signed".to_string())
+        })?;
+
+        let digest = Sha512::digest(&signed.bytecode);
+        verifying_key
+            .verify(&digest, &signed.signature)
+            .map_err(|e| TarlError::SignatureError(e.to_string()))?;
+
+        let source = core::str::from_utf8(&signed.bytecode)
+            .map_err(|_| TarlError::SignatureError("bytecode is not valid UTF-8".to_string()))?;
+        self.interpret(source, rng)
+    }
+
+    /// Walks the verified source line by line, interpreting the handful of
+    /// Thirsty-Lang statements the adapter understands: `drink <var> = "..."`
+    /// loads a plaintext value, `armor <var>` moves it into the AES-256-GCM
+    /// secret store, and `pour <var>` reads it back (decrypting on demand).
+    fn interpret(&mut self, source: &str, rng: &mut dyn SecretRng) -> Result<String, TarlError> {
+        let mut output = String::new();
+        for line in source.lines().map(str::trim) {
+            if let Some(rest) = line.strip_prefix("drink ") {
+                if let Some((name, value)) = rest.split_once('=') {
+                    let value = value.trim().trim_matches('"');
+                    self.secrets.drink(name.trim(), value.to_string())?;
+                }
+            } else if let Some(name) = line.strip_prefix("armor ") {
+                self.secrets.armor(name.trim(), rng)?;
+            } else if let Some(name) = line.strip_prefix("pour ") {
+                output.push_str(&self.secrets.pour(name.trim())?);
+                output.push('\n');
+            }
+        }
+        Ok(output)
+    }
+
+    /// Returns the VM's raw variable table; armored secrets come back as
+    /// ciphertext only, never the plaintext value.
+    pub fn dump_memory(&self) -> HashMap<String, Vec<u8>> {
+        self.secrets.dump_memory()
+    }
+
+    /// Creates a VM with a launched SGX enclave whose attestation quote
+    /// `attestation_report` returns. Armored secrets are still encrypted in
+    /// host memory by `SecretStore`, not sealed in the enclave. The VM has no
+    /// signing key. Requires the `sgx` feature.
+    #[cfg(feature = "sgx")]
+    pub fn new_enclave(
+        intent: &str,
+        scope: &str,
+        authority: &str,
+        constraints: Vec<String>,
+    ) -> Result<TARL, TarlError> {
+        let enclave = enclave::Enclave::launch()?;
+        Ok(TARL {
+            version: "2.0",
+            intent: intent.to_string(),
+            scope: scope.to_string(),
+            authority: authority.to_string(),
+            constraints,
+            signing_key: None,
+            verifying_key: None,
+            signed_bytecode: None,
+            secrets: SecretStore::default(),
+            enclave: Some(enclave),
+        })
+    }
+
+    /// Returns the enclave's remote-attestation quote so a caller can verify
+    /// its measurement before sending it any secrets. Requires the `sgx`
+    /// feature and a VM built with `new_enclave`.
+    #[cfg(feature = "sgx")]
+    pub fn attestation_report(&self) -> Result<Vec<u8>, TarlError> {
+        self.enclave
+            .as_ref()
+            .ok_or_else(|| TarlError::EnclaveError("VM has no enclave; use TARL::new_enclave".to_string()))?
+            .attestation_report()
+    }
+
+    /// Caps how many bytes of encrypted secret heap this VM may claim, so a
+    /// Cerberus guard agent running in a constrained environment can't let a
+    /// single shield exhaust host memory. Allocations beyond the limit return
+    /// `TarlError::OutOfMemory` rather than panicking.
+    pub fn with_capacity_limit(mut self, bytes: usize) -> Self {
+        self.secrets.capacity_limit = Some(bytes);
+        self
+    }
+}
+
+/// The kind of escape hatch a `BypassScanner` found in a piece of host code.
+/// `BypassScanner` itself is a host-side lint (it shells out to `syn` and
+/// reads files from disk) and is only available with the `std` feature.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ViolationKind {
+    UnsafeBlockOrFn,
+    Transmute,
+    RawPointerCast,
+    PointerArithmetic,
+    ExternBlock,
+    InlineAsm,
+    StaticMut,
+}
+
+#[cfg(feature = "std")]
+impl std::fmt::Display for ViolationKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let label = match self {
+            ViolationKind::UnsafeBlockOrFn => "unsafe block/fn",
+            ViolationKind::Transmute => "mem::transmute",
+            ViolationKind::RawPointerCast => "raw pointer cast",
+            ViolationKind::PointerArithmetic => "pointer arithmetic",
+            ViolationKind::ExternBlock => "extern block",
+            ViolationKind::InlineAsm => "inline asm",
+            ViolationKind::StaticMut => "static mut",
+        };
+        write!(f, "{}", label)
+    }
+}
+
+#[cfg(feature = "std")]
+/// One escape hatch found by `BypassScanner`, with enough location
+/// information to point a reviewer at the offending line.
+#[derive(Debug, Clone)]
+pub struct Violation {
+    pub kind: ViolationKind,
+    pub line: usize,
+    pub column: usize,
+    pub snippet: String,
+}
+
+#[cfg(feature = "std")]
+/// Walks a parsed Rust source file reporting every escape hatch the TARL VM
+/// claims to eliminate by architectural isolation: `unsafe`, `transmute`,
+/// raw pointer casts/arithmetic, `extern` blocks, inline asm, and `static
+/// mut`. Lets the `intent`/`scope`/`authority`/`constraints` story be
+/// enforced as a lint over an adapter's host code instead of asserted in
+/// prose.
+#[derive(Default)]
+pub struct BypassScanner {
+    violations: Vec<Violation>,
+}
+
+#[cfg(feature = "std")]
+use syn::spanned::Spanned;
+
+#[cfg(feature = "std")]
+impl BypassScanner {
+    /// Parses `source` as a Rust source file and returns every violation found.
+    pub fn scan_str(source: &str) -> Result<Vec<Violation>, syn::Error> {
+        let file = syn::parse_file(source)?;
+        let mut scanner = BypassScanner::default();
+        syn::visit::visit_file(&mut scanner, &file);
+        Ok(scanner.violations)
+    }
+
+    /// Reads `path` from disk and scans it the same way as `scan_str`.
+    pub fn scan_file(path: &std::path::Path) -> Result<Vec<Violation>, TarlError> {
+        let source = std::fs::read_to_string(path)
+            .map_err(|e| TarlError::CryptoError(format!("failed to read {}: {}", path.display(), e)))?;
+        BypassScanner::scan_str(&source)
+            .map_err(|e| TarlError::CryptoError(format!("failed to parse {}: {}", path.display(), e)))
+    }
+
+    fn record(&mut self, kind: ViolationKind, span: proc_macro2::Span, snippet: String) {
+        let start = span.start();
+        self.violations.push(Violation { kind, line: start.line, column: start.column, snippet });
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'ast> syn::visit::Visit<'ast> for BypassScanner {
+    fn visit_expr_unsafe(&mut self, node: &'ast syn::ExprUnsafe) {
+        self.record(ViolationKind::UnsafeBlockOrFn, node.unsafe_token.span(), "unsafe { .. }".to_string());
+        syn::visit::visit_expr_unsafe(self, node);
+    }
+
+    fn visit_item_fn(&mut self, node: &'ast syn::ItemFn) {
+        if node.sig.unsafety.is_some() {
+            self.record(
+                ViolationKind::UnsafeBlockOrFn,
+                node.sig.fn_token.span(),
+                format!("unsafe fn {}", node.sig.ident),
+            );
+        }
+        syn::visit::visit_item_fn(self, node);
+    }
+
+    fn visit_expr_call(&mut self, node: &'ast syn::ExprCall) {
+        if let syn::Expr::Path(path) = &*node.func {
+            let joined = path
+                .path
+                .segments
+                .iter()
+                .map(|s| s.ident.to_string())
+                .collect::<Vec<_>>()
+                .join("::");
+            if joined == "mem::transmute" || joined == "std::mem::transmute" || joined == "transmute" {
+                self.record(ViolationKind::Transmute, path.path.segments.last().unwrap().ident.span(), joined);
+            }
+        }
+        syn::visit::visit_expr_call(self, node);
+    }
+
+    fn visit_expr_cast(&mut self, node: &'ast syn::ExprCast) {
+        if matches!(&*node.ty, syn::Type::Ptr(_)) {
+            self.record(ViolationKind::RawPointerCast, node.as_token.span(), "as *const/*mut".to_string());
+        }
+        syn::visit::visit_expr_cast(self, node);
+    }
+
+    fn visit_expr_method_call(&mut self, node: &'ast syn::ExprMethodCall) {
+        let method = node.method.to_string();
+        if matches!(method.as_str(), "offset" | "add" | "sub" | "wrapping_offset" | "wrapping_add" | "wrapping_sub") {
+            self.record(ViolationKind::PointerArithmetic, node.method.span(), format!(".{}(..)", method));
+        }
+        syn::visit::visit_expr_method_call(self, node);
+    }
+
+    fn visit_item_foreign_mod(&mut self, node: &'ast syn::ItemForeignMod) {
+        self.record(ViolationKind::ExternBlock, node.abi.extern_token.span(), "extern block".to_string());
+        syn::visit::visit_item_foreign_mod(self, node);
+    }
+
+    fn visit_macro(&mut self, node: &'ast syn::Macro) {
+        if let Some(ident) = node.path.segments.last().map(|s| &s.ident) {
+            if ident == "asm" || ident == "global_asm" {
+                self.record(ViolationKind::InlineAsm, ident.span(), format!("{}!(..)", ident));
+            }
+        }
+        syn::visit::visit_macro(self, node);
+    }
+
+    fn visit_item_static(&mut self, node: &'ast syn::ItemStatic) {
+        if matches!(node.mutability, syn::StaticMutability::Mut(_)) {
+            self.record(ViolationKind::StaticMut, node.static_token.span(), format!("static mut {}", node.ident));
+        }
+        syn::visit::visit_item_static(self, node);
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dump_memory_never_exposes_armored_plaintext() {
+        let mut store = SecretStore::default();
+        let mut rng = OsRng;
+        let plaintext = "sk-PRODUCTION-SECRET-12345";
+
+        store.drink("api_key", plaintext.to_string()).unwrap();
+        store.armor("api_key", &mut rng).unwrap();
+
+        let dump = store.dump_memory();
+        let bytes = dump.get("api_key").expect("armored variable present in dump");
+        assert_ne!(bytes.as_slice(), plaintext.as_bytes());
+        assert!(
+            !bytes.windows(plaintext.len()).any(|window| window == plaintext.as_bytes()),
+            "dump_memory leaked the armored plaintext"
+        );
+
+        // Decrypting back through `pour` still recovers the original value.
+        assert_eq!(store.pour("api_key").unwrap(), plaintext);
+    }
+
+    #[test]
+    fn bypass_scanner_finds_every_escape_hatch() {
+        let source = r#"
+            static mut COUNTER: u32 = 0;
+
+            extern "C" {
+                fn ffi_call(x: i32) -> i32;
+            }
+
+            unsafe fn touch_raw(p: *const u8) -> u8 {
+                *p
+            }
+
+            fn main() {
+                unsafe {
+                    let x: u64 = std::mem::transmute(0u64);
+                    let ptr = 0usize as *const u8;
+                    let _ = ptr.add(1);
+                    std::arch::asm!("nop");
+                    let _ = x;
+                    let _ = ptr;
+                }
+            }
+        "#;
+
+        let violations = BypassScanner::scan_str(source).expect("sample source parses");
+        let kinds: Vec<ViolationKind> = violations.iter().map(|v| v.kind).collect();
+
+        assert!(kinds.contains(&ViolationKind::UnsafeBlockOrFn), "missed unsafe: {:?}", kinds);
+        assert!(kinds.contains(&ViolationKind::Transmute), "missed transmute: {:?}", kinds);
+        assert!(kinds.contains(&ViolationKind::RawPointerCast), "missed raw pointer cast: {:?}", kinds);
+        assert!(kinds.contains(&ViolationKind::PointerArithmetic), "missed pointer arithmetic: {:?}", kinds);
+        assert!(kinds.contains(&ViolationKind::ExternBlock), "missed extern block: {:?}", kinds);
+        assert!(kinds.contains(&ViolationKind::InlineAsm), "missed inline asm: {:?}", kinds);
+        assert!(kinds.contains(&ViolationKind::StaticMut), "missed static mut: {:?}", kinds);
+    }
+
+    #[test]
+    fn bypass_scanner_reports_nothing_on_clean_source() {
+        let source = r#"
+            fn add(a: u32, b: u32) -> u32 {
+                a + b
+            }
+
+            fn main() {
+                println!("{}", add(2, 3));
+            }
+        "#;
+
+        let violations = BypassScanner::scan_str(source).expect("clean source parses");
+        assert!(violations.is_empty(), "clean source should have no violations: {:?}", violations);
+    }
 }